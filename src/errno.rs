@@ -0,0 +1,52 @@
+//! A typed semihosting error, decoded from the host's `SYS_ERRNO` operation.
+
+use core::fmt;
+
+/// An error reported by a semihosting I/O operation.
+///
+/// This wraps the raw value returned by the ARM semihosting `SYS_ERRNO` operation, which the
+/// host sets after a failing call (for example `OPEN` returning a negative file descriptor, or
+/// `WRITE`/`READ` signaling an error). A handful of well-known values are exposed as associated
+/// constants; anything else is preserved as-is and printed numerically.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Errno(pub u32);
+
+impl Errno {
+    /// Operation not permitted.
+    pub const EPERM: Errno = Errno(1);
+    /// No such file or directory.
+    pub const ENOENT: Errno = Errno(2);
+    /// Bad file descriptor.
+    pub const EBADF: Errno = Errno(9);
+    /// Invalid argument.
+    pub const EINVAL: Errno = Errno(22);
+    /// Operation not supported by the debugger/host.
+    pub const ENOSYS: Errno = Errno(38);
+
+    /// Fetches the host's most recently recorded error code via `SYS_ERRNO`.
+    ///
+    /// Call this right after a semihosting operation reports failure; `SYS_ERRNO` takes no
+    /// arguments and simply returns the last error value the host recorded.
+    pub fn fetch() -> Errno {
+        Errno(unsafe { syscall!(ERRNO) } as u32)
+    }
+}
+
+impl fmt::Debug for Errno {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Errno").field(&self.0).finish()
+    }
+}
+
+impl fmt::Display for Errno {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Errno::EPERM => f.write_str("operation not permitted"),
+            Errno::ENOENT => f.write_str("no such file or directory"),
+            Errno::EBADF => f.write_str("bad file descriptor"),
+            Errno::EINVAL => f.write_str("invalid argument"),
+            Errno::ENOSYS => f.write_str("operation not supported by host"),
+            Errno(n) => write!(f, "host I/O error (errno {})", n),
+        }
+    }
+}
@@ -1,10 +1,15 @@
 //! IMPLEMENTATION DETAILS USED BY MACROS
+#![allow(missing_docs)]
 
 use core::fmt::{self, Write};
 
 use cortex_m::interrupt;
 
-use crate::hio::{self, HStderr, HStdout};
+use crate::errno::Errno;
+use crate::hio::{self, BufMode, HBufWriter, HStderr, HStdin, HStdout, Read};
+
+/// Capacity, in bytes, of the buffered `HSTDOUT_BUF`/`HSTDERR_BUF` writers.
+const BUF_SIZE: usize = 128;
 
 static mut HSTDOUT: Option<HStdout> = None;
 
@@ -14,11 +19,12 @@ pub fn hstdout_str(s: &str) {
             HSTDOUT = Some(hio::hstdout()?);
         }
 
-        HSTDOUT.as_mut().unwrap().write_str(s).map_err(drop)
+        let stdout = HSTDOUT.as_mut().unwrap();
+        stdout.write_str(s).map_err(|_| stdout.last_error())
     });
 
-    if result.is_err() {
-        error("hstdout");
+    if let Err(e) = result {
+        error("hstdout", e);
     }
 }
 
@@ -28,11 +34,61 @@ pub fn hstdout_fmt(args: fmt::Arguments) {
             HSTDOUT = Some(hio::hstdout()?);
         }
 
-        HSTDOUT.as_mut().unwrap().write_fmt(args).map_err(drop)
+        let stdout = HSTDOUT.as_mut().unwrap();
+        stdout.write_fmt(args).map_err(|_| stdout.last_error())
     });
 
-    if result.is_err() {
-        error("hstdout");
+    if let Err(e) = result {
+        error("hstdout", e);
+    }
+}
+
+static mut HSTDOUT_BUF: Option<HBufWriter<BUF_SIZE>> = None;
+
+/// Line-buffered counterpart of [`hstdout_str`], used by the buffered `hprint!` path.
+pub fn hstdout_str_buffered(s: &str) {
+    let result = interrupt::free(|_| unsafe {
+        if HSTDOUT_BUF.is_none() {
+            HSTDOUT_BUF = Some(hio::hstdout_buffered(BufMode::Line)?);
+        }
+
+        let stdout = HSTDOUT_BUF.as_mut().unwrap();
+        stdout.write_str(s).map_err(|_| stdout.last_error())
+    });
+
+    if let Err(e) = result {
+        error("hstdout", e);
+    }
+}
+
+/// Line-buffered counterpart of [`hstdout_fmt`], used by the buffered `hprint!` path.
+pub fn hstdout_fmt_buffered(args: fmt::Arguments) {
+    let result = interrupt::free(|_| unsafe {
+        if HSTDOUT_BUF.is_none() {
+            HSTDOUT_BUF = Some(hio::hstdout_buffered(BufMode::Line)?);
+        }
+
+        let stdout = HSTDOUT_BUF.as_mut().unwrap();
+        stdout.write_fmt(args).map_err(|_| stdout.last_error())
+    });
+
+    if let Err(e) = result {
+        error("hstdout", e);
+    }
+}
+
+/// Flushes any bytes buffered by the `hprint!`/`hprintln!` buffered path. A no-op if buffered
+/// output was never used.
+pub fn flush_stdout() {
+    let result = interrupt::free(|_| unsafe {
+        match HSTDOUT_BUF.as_mut() {
+            Some(buf) => buf.flush(),
+            None => Ok(()),
+        }
+    });
+
+    if let Err(e) = result {
+        error("hstdout", e);
     }
 }
 
@@ -44,11 +100,12 @@ pub fn hstderr_str(s: &str) {
             HSTDERR = Some(hio::hstderr()?);
         }
 
-        HSTDERR.as_mut().unwrap().write_str(s).map_err(drop)
+        let stderr = HSTDERR.as_mut().unwrap();
+        stderr.write_str(s).map_err(|_| stderr.last_error())
     });
 
-    if result.is_err() {
-        error("hstderr");
+    if let Err(e) = result {
+        error("hstderr", e);
     }
 }
 
@@ -58,15 +115,89 @@ pub fn hstderr_fmt(args: fmt::Arguments) {
             HSTDERR = Some(hio::hstderr()?);
         }
 
-        HSTDERR.as_mut().unwrap().write_fmt(args).map_err(drop)
+        let stderr = HSTDERR.as_mut().unwrap();
+        stderr.write_fmt(args).map_err(|_| stderr.last_error())
+    });
+
+    if let Err(e) = result {
+        error("hstderr", e);
+    }
+}
+
+static mut HSTDERR_BUF: Option<HBufWriter<BUF_SIZE>> = None;
+
+/// Line-buffered counterpart of [`hstderr_str`], used by the buffered `heprint!` path.
+pub fn hstderr_str_buffered(s: &str) {
+    let result = interrupt::free(|_| unsafe {
+        if HSTDERR_BUF.is_none() {
+            HSTDERR_BUF = Some(hio::hstderr_buffered(BufMode::Line)?);
+        }
+
+        let stderr = HSTDERR_BUF.as_mut().unwrap();
+        stderr.write_str(s).map_err(|_| stderr.last_error())
     });
 
-    if result.is_err() {
-        error("hstderr");
+    if let Err(e) = result {
+        error("hstderr", e);
     }
 }
 
+/// Line-buffered counterpart of [`hstderr_fmt`], used by the buffered `heprint!` path.
+pub fn hstderr_fmt_buffered(args: fmt::Arguments) {
+    let result = interrupt::free(|_| unsafe {
+        if HSTDERR_BUF.is_none() {
+            HSTDERR_BUF = Some(hio::hstderr_buffered(BufMode::Line)?);
+        }
+
+        let stderr = HSTDERR_BUF.as_mut().unwrap();
+        stderr.write_fmt(args).map_err(|_| stderr.last_error())
+    });
+
+    if let Err(e) = result {
+        error("hstderr", e);
+    }
+}
+
+/// Flushes any bytes buffered by the `heprint!`/`heprintln!` buffered path. A no-op if buffered
+/// output was never used.
+pub fn flush_stderr() {
+    let result = interrupt::free(|_| unsafe {
+        match HSTDERR_BUF.as_mut() {
+            Some(buf) => buf.flush(),
+            None => Ok(()),
+        }
+    });
+
+    if let Err(e) = result {
+        error("hstderr", e);
+    }
+}
+
+static mut HSTDIN: Option<HStdin> = None;
+
+/// Reads bytes into `buf`, used by the `hread!` macro.
+pub fn hstdin_read(buf: &mut [u8]) -> Result<usize, Errno> {
+    interrupt::free(|_| unsafe {
+        if HSTDIN.is_none() {
+            HSTDIN = Some(hio::hstdin()?);
+        }
+
+        HSTDIN.as_mut().unwrap().read(buf)
+    })
+}
+
+/// Reads a line into `buf`, used by the `hreadln!` macro.
+pub fn hstdin_read_line(buf: &mut [u8]) -> Result<usize, Errno> {
+    interrupt::free(|_| unsafe {
+        if HSTDIN.is_none() {
+            HSTDIN = Some(hio::hstdin()?);
+        }
+
+        Ok(HSTDIN.as_mut().unwrap().read_line(buf))
+    })
+}
+
 #[cold]
-fn error(label: &str) {
-    panic!("failed to print to {}", label);
+fn error(label: &str, e: Errno) -> ! {
+    panic!("failed to print to {}: {}", label, e);
 }
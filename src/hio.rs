@@ -0,0 +1,545 @@
+//! Host I/O
+
+use core::{cmp, fmt, slice};
+use core::fmt::Write;
+use core::ptr;
+
+use crate::errno::Errno;
+use crate::ArgBlock;
+
+/// File descriptors
+static mut STDOUT: isize = -1;
+static mut STDERR: isize = -1;
+static mut STDIN: isize = -1;
+
+/// Host's standard error
+pub struct HStderr {
+    last_err: Option<Errno>,
+}
+
+/// Host's standard output
+pub struct HStdout {
+    last_err: Option<Errno>,
+}
+
+/// Host's standard input
+pub struct HStdin {
+    _0: (),
+}
+
+/// Returns the host's stdout file descriptor, or a negative value if it hasn't been opened yet.
+pub fn get_stdout() -> isize {
+    // Safe: 32-bit accesses are atomic on ARM
+    unsafe{ptr::read_volatile(&STDOUT)}
+}
+
+/// Returns the host's stderr file descriptor, or a negative value if it hasn't been opened yet.
+pub fn get_stderr() -> isize {
+    // Safe: 32-bit accesses are atomic on ARM
+    unsafe{ptr::read_volatile(&STDERR)}
+}
+
+/// Returns the host's stdin file descriptor, or a negative value if it hasn't been opened yet.
+pub fn get_stdin() -> isize {
+    // Safe: 32-bit accesses are atomic on ARM
+    unsafe{ptr::read_volatile(&STDIN)}
+}
+
+/// Open stdout and stderr.
+pub fn open_streams() -> Result<(), Errno> {
+    // Special terminal path
+    let path = ":tt";
+
+    let stdout_fd = unsafe { syscall!(OPEN, path.as_bytes().as_ptr(), 4, path.len()) } as isize;
+    let stderr_fd = unsafe { syscall!(OPEN, path.as_bytes().as_ptr(), 8, path.len()) } as isize;
+
+    // Safe: 32-bit accesses are atomic on ARM
+    unsafe {
+        ptr::write_volatile(&mut STDOUT, stdout_fd);
+        ptr::write_volatile(&mut STDERR, stderr_fd);
+    }
+
+    if stdout_fd < 0 || stderr_fd < 0 {
+        Err(Errno::fetch())
+    } else {
+        Ok(())
+    }
+}
+
+/// Open stdin. Kept separate from [`open_streams`] so that a host/debugger that won't open `:tt`
+/// for input doesn't break stdout/stderr, which `hprint!`/`hprintln!`/`heprintln!` depend on.
+fn open_stdin() -> Result<(), Errno> {
+    // Special terminal path; flag 0 opens it for reading instead of the 4/8 used for stdout/stderr
+    let path = ":tt";
+
+    let stdin_fd = unsafe { syscall!(OPEN, path.as_bytes().as_ptr(), 0, path.len()) } as isize;
+
+    // Safe: 32-bit accesses are atomic on ARM
+    unsafe {
+        ptr::write_volatile(&mut STDIN, stdin_fd);
+    }
+
+    if stdin_fd < 0 {
+        Err(Errno::fetch())
+    } else {
+        Ok(())
+    }
+}
+
+/// Write the contents of `buffer` to `fd`. If `fd` is less than zero, do nothing and return
+/// `Err(Errno::EBADF)`.
+pub fn write_all(fd: isize, mut buffer: &[u8]) -> Result<(), Errno> {
+    if fd < 0 {
+        return Err(Errno::EBADF);
+    }
+
+    while !buffer.is_empty() {
+        match unsafe { syscall!(WRITE, fd, buffer.as_ptr(), buffer.len()) } {
+            // Done
+            0 => return Ok(()),
+            // `n` bytes were not written
+            n if n <= buffer.len() && n > 0 => {
+                let offset = (buffer.len() - n) as isize;
+                buffer = unsafe {
+                    slice::from_raw_parts(buffer.as_ptr().offset(offset as isize), n)
+                };
+            },
+            // error writing bytes, most likely write() returned -1
+            _ => return Err(Errno::fetch()),
+        }
+    }
+
+    Ok(())
+}
+
+/// Construct a handle to the host's standard output, opening the host streams first if they
+/// haven't been opened yet.
+pub fn hstdout() -> Result<HStdout, Errno> {
+    if get_stdout() < 0 {
+        open_streams()?;
+    }
+
+    Ok(HStdout { last_err: None })
+}
+
+/// Construct a handle to the host's standard error, opening the host streams first if they
+/// haven't been opened yet.
+pub fn hstderr() -> Result<HStderr, Errno> {
+    if get_stderr() < 0 {
+        open_streams()?;
+    }
+
+    Ok(HStderr { last_err: None })
+}
+
+/// Construct a handle to the host's standard input, opening the host's stdin first if it
+/// hasn't been opened yet.
+pub fn hstdin() -> Result<HStdin, Errno> {
+    if get_stdin() < 0 {
+        open_stdin()?;
+    }
+
+    Ok(HStdin { _0: () })
+}
+
+impl HStdin {
+    /// Reads a single byte from the host's stdin.
+    pub fn read_byte(&mut self) -> u8 {
+        unsafe { syscall!(READC) as u8 }
+    }
+
+    /// Reads bytes into `buf` until a `\n` is seen or `buf` fills up, returning the number of
+    /// bytes read (not including the newline).
+    ///
+    /// Reads one byte at a time via [`read_byte`](HStdin::read_byte) (`SYS_READC`) rather than
+    /// through a bulk [`Read::read`] (`SYS_READ`): a bulk read can return bytes from *past* the
+    /// line's `\n` in the same trap, and since semihosting has no way to push them back, they'd be
+    /// silently lost before the next call could see them.
+    pub fn read_line(&mut self, buf: &mut [u8]) -> usize {
+        let mut n = 0;
+
+        while n < buf.len() {
+            let b = self.read_byte();
+
+            if b == b'\n' {
+                break;
+            }
+
+            buf[n] = b;
+            n += 1;
+        }
+
+        n
+    }
+}
+
+impl HStderr {
+    fn write_all(&mut self, buffer: &[u8]) -> Result<(), Errno> {
+        write_all(get_stderr(), buffer).inspect_err(|&e| self.last_err = Some(e))
+    }
+
+    /// The `Errno` behind the most recent `fmt::Error` this handle returned, or the host's
+    /// `SYS_ERRNO` value if no write error was recorded.
+    pub(crate) fn last_error(&self) -> Errno {
+        self.last_err.unwrap_or_else(Errno::fetch)
+    }
+}
+
+impl HStdout {
+    fn write_all(&mut self, buffer: &[u8]) -> Result<(), Errno> {
+        write_all(get_stdout(), buffer).inspect_err(|&e| self.last_err = Some(e))
+    }
+
+    /// The `Errno` behind the most recent `fmt::Error` this handle returned, or the host's
+    /// `SYS_ERRNO` value if no write error was recorded.
+    pub(crate) fn last_error(&self) -> Errno {
+        self.last_err.unwrap_or_else(Errno::fetch)
+    }
+}
+
+impl Write for HStderr {
+    // `fmt::Write` can only report `fmt::Error`; the real cause is stashed in `last_err` for
+    // callers (e.g. `export`) that need to report it.
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.write_all(s.as_bytes()).or(Err(fmt::Error))
+    }
+}
+
+impl Write for HStdout {
+    // See the note on `HStderr`'s `write_str` impl.
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.write_all(s.as_bytes()).or(Err(fmt::Error))
+    }
+}
+
+/// Write a `buffer` to the host's stderr
+pub fn ewrite(buffer: &[u8]) -> Result<(), Errno> {
+    HStderr { last_err: None }.write_all(buffer)
+}
+
+/// Write `fmt::Arguments` to the host's stderr
+pub fn ewrite_fmt(args: fmt::Arguments) -> fmt::Result {
+    HStderr { last_err: None }.write_fmt(args)
+}
+
+/// Write a `string` to the host's stderr
+pub fn ewrite_str(string: &str) -> Result<(), Errno> {
+    HStderr { last_err: None }.write_all(string.as_bytes())
+}
+
+/// Write a `buffer` to the host's stdout
+pub fn write(buffer: &[u8]) -> Result<(), Errno> {
+    HStdout { last_err: None }.write_all(buffer)
+}
+
+/// Write `fmt::Arguments` to the host's stdout
+pub fn write_fmt(args: fmt::Arguments) -> fmt::Result {
+    HStdout { last_err: None }.write_fmt(args)
+}
+
+/// Write a `string` to the host's stdout
+pub fn write_str(string: &str) -> Result<(), Errno> {
+    HStdout { last_err: None }.write_all(string.as_bytes())
+}
+
+/// Buffering strategy used by a [`HBufWriter`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum BufMode {
+    /// Only flush when the buffer fills up or `flush` is called explicitly.
+    Full,
+    /// Additionally flush whenever a `\n` is written.
+    Line,
+}
+
+/// A fixed-capacity, non-allocating buffered writer over a host stream.
+///
+/// Bytes written through [`core::fmt::Write`] accumulate in an internal `N`-byte buffer and are
+/// only handed to the host with a single `SYS_WRITE` once the buffer fills up,
+/// [`flush`](HBufWriter::flush) is called, or -- in [`BufMode::Line`] mode -- a `\n` is written.
+/// Construct one with [`hstdout_buffered`]/[`hstderr_buffered`].
+pub struct HBufWriter<const N: usize> {
+    fd: isize,
+    mode: BufMode,
+    buf: [u8; N],
+    len: usize,
+    last_err: Option<Errno>,
+}
+
+impl<const N: usize> HBufWriter<N> {
+    fn new(fd: isize, mode: BufMode) -> Self {
+        HBufWriter {
+            fd,
+            mode,
+            buf: [0; N],
+            len: 0,
+            last_err: None,
+        }
+    }
+
+    /// Sends any buffered bytes to the host now, regardless of buffering mode.
+    pub fn flush(&mut self) -> Result<(), Errno> {
+        if self.len == 0 {
+            return Ok(());
+        }
+
+        write_all(self.fd, &self.buf[..self.len]).inspect_err(|&e| self.last_err = Some(e))?;
+        self.len = 0;
+
+        Ok(())
+    }
+
+    fn write_bytes(&mut self, mut bytes: &[u8]) -> Result<(), Errno> {
+        while !bytes.is_empty() {
+            if self.len == N {
+                self.flush()?;
+            }
+
+            let n = cmp::min(N - self.len, bytes.len());
+            self.buf[self.len..self.len + n].copy_from_slice(&bytes[..n]);
+            self.len += n;
+            bytes = &bytes[n..];
+
+            if self.mode == BufMode::Line {
+                if let Some(pos) = self.buf[..self.len].iter().rposition(|&b| b == b'\n') {
+                    // Emit everything up to and including the last newline in one trap, and
+                    // keep anything after it buffered.
+                    write_all(self.fd, &self.buf[..=pos]).inspect_err(|&e| self.last_err = Some(e))?;
+
+                    let tail = self.len - (pos + 1);
+                    self.buf.copy_within(pos + 1..self.len, 0);
+                    self.len = tail;
+                }
+            }
+
+            if self.len == N {
+                self.flush()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The `Errno` behind the most recent `fmt::Error` this writer returned, or the host's
+    /// `SYS_ERRNO` value if no write error was recorded.
+    pub(crate) fn last_error(&self) -> Errno {
+        self.last_err.unwrap_or_else(Errno::fetch)
+    }
+}
+
+impl<const N: usize> Write for HBufWriter<N> {
+    // See the note on `HStderr`'s `write_str` impl; the real cause is stashed in `last_err`.
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.write_bytes(s.as_bytes()).or(Err(fmt::Error))
+    }
+}
+
+/// Construct a fixed-capacity, buffered handle to the host's standard output.
+///
+/// `N` is the buffer's capacity in bytes. See [`HBufWriter`] for the buffering semantics.
+pub fn hstdout_buffered<const N: usize>(mode: BufMode) -> Result<HBufWriter<N>, Errno> {
+    if get_stdout() < 0 {
+        open_streams()?;
+    }
+
+    Ok(HBufWriter::new(get_stdout(), mode))
+}
+
+/// Construct a fixed-capacity, buffered handle to the host's standard error.
+///
+/// `N` is the buffer's capacity in bytes. See [`HBufWriter`] for the buffering semantics.
+pub fn hstderr_buffered<const N: usize>(mode: BufMode) -> Result<HBufWriter<N>, Errno> {
+    if get_stderr() < 0 {
+        open_streams()?;
+    }
+
+    Ok(HBufWriter::new(get_stderr(), mode))
+}
+
+// Re-exported so callers can flush the buffered `hprint!`/`heprint!` state without reaching into
+// `export` themselves.
+pub use crate::export::{flush_stderr, flush_stdout};
+
+/// The mode a [`File`] is opened in, mirroring the C `fopen` modes ARM semihosting's `SYS_OPEN`
+/// is built around.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum OpenMode {
+    /// `"r"` -- open an existing file for reading.
+    Read,
+    /// `"r+"` -- open an existing file for reading and writing.
+    ReadWrite,
+    /// `"w"` -- create a file (truncating it if it exists) for writing.
+    Write,
+    /// `"w+"` -- create a file (truncating it if it exists) for reading and writing.
+    ReadWriteTruncate,
+    /// `"a"` -- open a file for appending, creating it if it doesn't exist.
+    Append,
+    /// `"a+"` -- open a file for reading and appending, creating it if it doesn't exist.
+    ReadAppend,
+}
+
+impl OpenMode {
+    fn as_raw(self) -> usize {
+        match self {
+            OpenMode::Read => 0,
+            OpenMode::ReadWrite => 2,
+            OpenMode::Write => 4,
+            OpenMode::ReadWriteTruncate => 6,
+            OpenMode::Append => 8,
+            OpenMode::ReadAppend => 10,
+        }
+    }
+}
+
+/// A minimal, `core`-only counterpart to `std::io::Read`.
+pub trait Read {
+    /// Reads some bytes from this source into `buf`, returning how many bytes were read.
+    ///
+    /// `Ok(0)` means `buf` was empty or the source is at EOF.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Errno>;
+}
+
+impl Read for HStdin {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Errno> {
+        // SYS_READ returns the number of bytes *not* read, not the number read: `n == 0` means
+        // `buf` was filled completely, `n == buf.len()` means EOF/no data was available.
+        let not_read = unsafe { syscall!(READ, get_stdin(), buf.as_mut_ptr(), buf.len()) };
+
+        if not_read > buf.len() {
+            return Err(Errno::fetch());
+        }
+
+        Ok(buf.len() - not_read)
+    }
+}
+
+/// A file on the host's filesystem, opened through ARM semihosting.
+///
+/// Backed by `SYS_OPEN`/`SYS_READ`/`SYS_WRITE`/`SYS_SEEK`/`SYS_FLEN`/`SYS_ISTTY`/`SYS_CLOSE`, this
+/// lets firmware load test vectors from, and dump results to, files on the debugging host instead
+/// of just the console.
+pub struct File {
+    fd: isize,
+}
+
+impl File {
+    /// Opens `path` on the host in the given `mode`.
+    pub fn open(path: &str, mode: OpenMode) -> Result<File, Errno> {
+        let fd =
+            unsafe { syscall!(OPEN, path.as_bytes().as_ptr(), mode.as_raw(), path.len()) } as isize;
+
+        if fd < 0 {
+            Err(Errno::fetch())
+        } else {
+            Ok(File { fd })
+        }
+    }
+
+    /// Writes the entirety of `buffer` to this file.
+    pub fn write(&mut self, buffer: &[u8]) -> Result<(), Errno> {
+        write_all(self.fd, buffer)
+    }
+
+    /// Moves the file's read/write position to the given absolute byte offset.
+    pub fn seek(&mut self, pos: usize) -> Result<(), Errno> {
+        if unsafe { syscall!(SEEK, self.fd, pos) } as isize != 0 {
+            Err(Errno::fetch())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns the current length of the file, in bytes.
+    pub fn len(&self) -> Result<usize, Errno> {
+        let len = unsafe { syscall!(FLEN, self.fd) } as isize;
+
+        if len < 0 {
+            Err(Errno::fetch())
+        } else {
+            Ok(len as usize)
+        }
+    }
+
+    /// Reports whether the file is currently empty.
+    pub fn is_empty(&self) -> Result<bool, Errno> {
+        self.len().map(|len| len == 0)
+    }
+
+    /// Reports whether this file is connected to an interactive device (a terminal) rather than,
+    /// e.g., a plain file or a pipe.
+    pub fn is_tty(&self) -> bool {
+        unsafe { syscall!(ISTTY, self.fd) == 1 }
+    }
+
+    /// Closes the file, reporting any error from the host's `SYS_CLOSE` call.
+    ///
+    /// Dropping a `File` without calling this also closes it, but silently ignores errors.
+    pub fn close(mut self) -> Result<(), Errno> {
+        self.close_fd()
+    }
+
+    fn close_fd(&mut self) -> Result<(), Errno> {
+        if self.fd < 0 {
+            return Ok(());
+        }
+
+        let result = unsafe { syscall!(CLOSE, self.fd) } as isize;
+        self.fd = -1;
+
+        if result != 0 {
+            Err(Errno::fetch())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Read for File {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Errno> {
+        // SYS_READ returns the number of bytes *not* filled, not the number read.
+        let not_filled = unsafe { syscall!(READ, self.fd, buf.as_mut_ptr(), buf.len()) };
+
+        if not_filled > buf.len() {
+            return Err(Errno::fetch());
+        }
+
+        Ok(buf.len() - not_filled)
+    }
+}
+
+impl Write for File {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.write(s.as_bytes()).or(Err(fmt::Error))
+    }
+}
+
+impl Drop for File {
+    fn drop(&mut self) {
+        let _ = self.close_fd();
+    }
+}
+
+/// Heap and stack bounds as reported by the host via `SYS_HEAPINFO`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HeapInfo {
+    /// Lowest address of the heap.
+    pub heap_base: usize,
+    /// Address one past the end of the heap.
+    pub heap_limit: usize,
+    /// Lowest address of the stack.
+    pub stack_base: usize,
+    /// Highest address of the stack.
+    pub stack_limit: usize,
+}
+
+/// Fetches the heap and stack bounds the host has configured for this target.
+pub fn heap_info() -> HeapInfo {
+    let mut block = ArgBlock::<4>::new();
+    unsafe { syscall!(HEAPINFO, block.as_mut_ptr()) };
+
+    HeapInfo {
+        heap_base: block.get(0),
+        heap_limit: block.get(1),
+        stack_base: block.get(2),
+        stack_limit: block.get(3),
+    }
+}
@@ -128,9 +128,14 @@
 mod macros;
 
 pub mod debug;
+pub mod errno;
+#[doc(hidden)]
+pub mod export;
 pub mod hio;
 pub mod nr;
 
+pub use crate::errno::Errno;
+
 #[cfg(all(thumb, not(feature = "inline-asm")))]
 extern "C" {
     fn __syscall(nr: usize, arg: usize) -> usize;
@@ -157,6 +162,44 @@ pub unsafe fn syscall1(_nr: usize, _arg: usize) -> usize {
         }
 
         #[cfg(not(thumb))]
-        () => unimplemented!(),
+        () => unimplemented!()
+    }
+}
+
+/// A fixed-size, zero-initialized block of `usize` words for semihosting operations that read or
+/// write more data than fits in a plain argument list.
+///
+/// Some operations (e.g. `SYS_HEAPINFO`) fill in a result block rather than returning a value
+/// directly, and expect the *argument* block to hold a pointer to that result block rather than
+/// the result block itself -- `SYS_HEAPINFO`'s argument word is a pointer to a pointer to the
+/// four-word result block, which the host fills in (heap base, heap limit, stack base, stack
+/// limit). Build the result block with `ArgBlock::new()` and pass `block.as_mut_ptr()` through
+/// [`syscall!`](crate::syscall) like any other argument -- the macro's own `&[ptr]` argument-block
+/// boxing *is* the extra pointer indirection the op needs -- then read the results back with
+/// [`get`](ArgBlock::get). See [`hio::heap_info`](crate::hio::heap_info) for a worked example.
+pub struct ArgBlock<const N: usize> {
+    words: [usize; N],
+}
+
+impl<const N: usize> ArgBlock<N> {
+    /// Creates a new, zeroed argument block.
+    pub const fn new() -> Self {
+        ArgBlock { words: [0; N] }
+    }
+
+    /// A pointer to the block, suitable for passing as a `syscall!` argument.
+    pub fn as_mut_ptr(&mut self) -> *mut usize {
+        self.words.as_mut_ptr()
+    }
+
+    /// Reads back the word at `index`, as written by the host after the trap returns.
+    pub fn get(&self, index: usize) -> usize {
+        self.words[index]
+    }
+}
+
+impl<const N: usize> Default for ArgBlock<N> {
+    fn default() -> Self {
+        Self::new()
     }
 }
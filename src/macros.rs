@@ -1,22 +1,21 @@
 /// Variable argument version of `syscall`
+///
+/// Accepts one or more arguments, assembling them into a `[usize; N]` argument block and
+/// routing the call through [`syscall`](crate::syscall); with no arguments it calls
+/// [`syscall1`](crate::syscall1) with `0`. Operations that write results back into
+/// a caller-provided block (e.g. `HEAPINFO`) should build that block with
+/// [`ArgBlock`](crate::ArgBlock) and pass `block.as_mut_ptr()` as the lone argument here.
+///
+/// The fixed arms for one to four arguments have been replaced outright by the single variadic
+/// arm below rather than kept around deprecated. Every prior `syscall!(NR, a, b, ...)` call form
+/// still compiles unchanged against that arm.
 #[macro_export]
 macro_rules! syscall {
     ($nr:ident) => {
         $crate::syscall1($crate::nr::$nr, 0)
     };
-    ($nr:ident, $a1:expr) => {
-        $crate::syscall($crate::nr::$nr, &[$a1 as usize])
-    };
-    ($nr:ident, $a1:expr, $a2:expr) => {
-        $crate::syscall($crate::nr::$nr, &[$a1 as usize, $a2 as usize])
-    };
-    ($nr:ident, $a1:expr, $a2:expr, $a3:expr) => {
-        $crate::syscall($crate::nr::$nr, &[$a1 as usize, $a2 as usize,
-                                           $a3 as usize])
-    };
-    ($nr:ident, $a1:expr, $a2:expr, $a3:expr, $a4:expr) => {
-        $crate::syscall($crate::nr::$nr, &[$a1 as usize, $a2 as usize,
-                                           $a3 as usize, $a4 as usize])
+    ($nr:ident, $($a:expr),+ $(,)?) => {
+        $crate::syscall($crate::nr::$nr, &[$($a as usize),+])
     };
 }
 
@@ -85,3 +84,84 @@ macro_rules! heprintln {
         $crate::export::hstderr_fmt(format_args!(concat!($s, "\n"), $($tt)*))
     };
 }
+
+/// Macro for printing to the HOST standard output through a line-buffered writer.
+///
+/// Unlike [`hprint!`], repeated calls are coalesced into a single `SYS_WRITE` per line, which is
+/// much cheaper when logging a lot of output. Call [`hio::flush_stdout`](crate::hio::flush_stdout)
+/// to force out a partial, unterminated line.
+#[macro_export]
+macro_rules! hprint_buffered {
+    ($s:expr) => {
+        $crate::export::hstdout_str_buffered($s)
+    };
+    ($($tt:tt)*) => {
+        $crate::export::hstdout_fmt_buffered(format_args!($($tt)*))
+    };
+}
+
+/// Macro for printing to the HOST standard output through a line-buffered writer, with a newline.
+///
+/// See [`hprint_buffered!`].
+#[macro_export]
+macro_rules! hprintln_buffered {
+    () => {
+        $crate::export::hstdout_str_buffered("\n")
+    };
+    ($s:expr) => {
+        $crate::export::hstdout_str_buffered(concat!($s, "\n"))
+    };
+    ($s:expr, $($tt:tt)*) => {
+        $crate::export::hstdout_fmt_buffered(format_args!(concat!($s, "\n"), $($tt)*))
+    };
+}
+
+/// Macro for printing to the HOST standard error through a line-buffered writer.
+///
+/// See [`hprint_buffered!`].
+#[macro_export]
+macro_rules! heprint_buffered {
+    ($s:expr) => {
+        $crate::export::hstderr_str_buffered($s)
+    };
+    ($($tt:tt)*) => {
+        $crate::export::hstderr_fmt_buffered(format_args!($($tt)*))
+    };
+}
+
+/// Macro for printing to the HOST standard error through a line-buffered writer, with a newline.
+///
+/// See [`hprint_buffered!`].
+#[macro_export]
+macro_rules! heprintln_buffered {
+    () => {
+        $crate::export::hstderr_str_buffered("\n")
+    };
+    ($s:expr) => {
+        $crate::export::hstderr_str_buffered(concat!($s, "\n"))
+    };
+    ($s:expr, $($tt:tt)*) => {
+        $crate::export::hstderr_fmt_buffered(format_args!(concat!($s, "\n"), $($tt)*))
+    };
+}
+
+/// Macro for reading from the HOST standard input into a byte buffer.
+///
+/// This macro returns a `Result<usize, Errno>` value with the number of bytes read.
+#[macro_export]
+macro_rules! hread {
+    ($buf:expr) => {
+        $crate::export::hstdin_read($buf)
+    };
+}
+
+/// Macro for reading a line from the HOST standard input into a byte buffer.
+///
+/// Reads until a `\n` is seen or `$buf` fills up. This macro returns a `Result<usize, Errno>`
+/// value with the number of bytes read, not including the newline.
+#[macro_export]
+macro_rules! hreadln {
+    ($buf:expr) => {
+        $crate::export::hstdin_read_line($buf)
+    };
+}
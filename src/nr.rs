@@ -0,0 +1,35 @@
+//! Semihosting operation numbers, as specified in
+//! ['Chapter 8 - Semihosting' of the 'ARM Compiler toolchain Version 5.0'][pdf] manual.
+//!
+//! [pdf]: http://infocenter.arm.com/help/topic/com.arm.doc.dui0471e/DUI0471E_developing_for_arm_processors.pdf
+
+/// Open a file on the host.
+pub const OPEN: usize = 0x01;
+
+/// Close a file previously opened with [`OPEN`].
+pub const CLOSE: usize = 0x02;
+
+/// Write to a file, or to the host's stdout/stderr via the `:tt` pseudo-file.
+pub const WRITE: usize = 0x05;
+
+/// Read from a file, or from the host's stdin via the `:tt` pseudo-file.
+pub const READ: usize = 0x06;
+
+/// Read a single byte from the host's stdin.
+pub const READC: usize = 0x07;
+
+/// Check whether a file is connected to an interactive device.
+pub const ISTTY: usize = 0x09;
+
+/// Seek to an absolute position within a file.
+pub const SEEK: usize = 0x0a;
+
+/// Get the length of a file.
+pub const FLEN: usize = 0x0c;
+
+/// Fetch the host's most recently recorded error number.
+pub const ERRNO: usize = 0x13;
+
+/// Fetch heap/stack bounds. Takes a single pointer to a 4-word block (heap base, heap limit,
+/// stack base, stack limit) that the host fills in -- see [`ArgBlock`](crate::ArgBlock).
+pub const HEAPINFO: usize = 0x16;